@@ -0,0 +1,101 @@
+use crate::stream::{Fuse, Stream};
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the
+    /// [`buffer_unordered`](super::StreamExt::buffer_unordered) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct BufferUnordered<St>
+    where
+        St: Stream,
+        St::Item: Future,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        in_progress: Vec<Pin<Box<St::Item>>>,
+        max: usize,
+        done: bool,
+    }
+}
+
+impl<St> BufferUnordered<St>
+where
+    St: Stream,
+    St::Item: Future,
+{
+    pub(super) fn new(stream: St, n: usize) -> BufferUnordered<St> {
+        BufferUnordered {
+            stream: Fuse::new(stream),
+            in_progress: Vec::with_capacity(n),
+            max: n,
+            done: false,
+        }
+    }
+}
+
+impl<St> fmt::Debug for BufferUnordered<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferUnordered")
+            .field("stream", &self.stream)
+            .field("in_progress", &self.in_progress.len())
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<St> Stream for BufferUnordered<St>
+where
+    St: Stream,
+    St::Item: Future,
+{
+    type Item = <St::Item as Future>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        // Pull futures from the source, keeping at most `max` in flight.
+        while !*me.done && me.in_progress.len() < *me.max {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(fut)) => me.in_progress.push(Box::pin(fut)),
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        // Poll every in-flight future, yielding the first one to complete.
+        let mut i = 0;
+        while i < me.in_progress.len() {
+            if let Poll::Ready(output) = me.in_progress[i].as_mut().poll(cx) {
+                me.in_progress.swap_remove(i);
+                return Poll::Ready(Some(output));
+            }
+            i += 1;
+        }
+
+        if *me.done && me.in_progress.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queued = self.in_progress.len();
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = lower.saturating_add(queued);
+        let upper = upper.and_then(|upper| upper.checked_add(queued));
+
+        (lower, upper)
+    }
+}
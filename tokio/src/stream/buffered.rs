@@ -0,0 +1,117 @@
+use crate::stream::{Fuse, Stream};
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::collections::VecDeque;
+
+use pin_project_lite::pin_project;
+
+// A slot in the ordered in-flight queue: either a future still running or the
+// output of one that has already completed and is waiting to reach the head.
+enum Slot<Fut: Future> {
+    Pending(Pin<Box<Fut>>),
+    Ready(Fut::Output),
+}
+
+pin_project! {
+    /// Stream returned by the [`buffered`](super::StreamExt::buffered) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Buffered<St>
+    where
+        St: Stream,
+        St::Item: Future,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        in_progress: VecDeque<Slot<St::Item>>,
+        max: usize,
+        done: bool,
+    }
+}
+
+impl<St> Buffered<St>
+where
+    St: Stream,
+    St::Item: Future,
+{
+    pub(super) fn new(stream: St, n: usize) -> Buffered<St> {
+        Buffered {
+            stream: Fuse::new(stream),
+            in_progress: VecDeque::with_capacity(n),
+            max: n,
+            done: false,
+        }
+    }
+}
+
+impl<St> fmt::Debug for Buffered<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Buffered")
+            .field("stream", &self.stream)
+            .field("in_progress", &self.in_progress.len())
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<St> Stream for Buffered<St>
+where
+    St: Stream,
+    St::Item: Future,
+{
+    type Item = <St::Item as Future>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        // Pull futures from the source, keeping at most `max` in flight.
+        while !*me.done && me.in_progress.len() < *me.max {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(fut)) => me.in_progress.push_back(Slot::Pending(Box::pin(fut))),
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        // Poll every still-running future so they all make progress
+        // concurrently and register their wakers, caching any output so a
+        // completed future is never polled again. Order is preserved by only
+        // yielding once the head slot is ready.
+        for slot in me.in_progress.iter_mut() {
+            if let Slot::Pending(fut) = slot {
+                if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+                    *slot = Slot::Ready(output);
+                }
+            }
+        }
+
+        if let Some(Slot::Ready(_)) = me.in_progress.front() {
+            match me.in_progress.pop_front() {
+                Some(Slot::Ready(output)) => return Poll::Ready(Some(output)),
+                _ => unreachable!(),
+            }
+        }
+
+        if me.in_progress.is_empty() && *me.done {
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queued = self.in_progress.len();
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = lower.saturating_add(queued);
+        let upper = upper.and_then(|upper| upper.checked_add(queued));
+
+        (lower, upper)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::stream::{Fuse, Stream};
+
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`chunks`](super::StreamExt::chunks) method.
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct Chunks<St>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        items: Vec<St::Item>,
+        cap: usize,
+    }
+}
+
+impl<St: Stream> Chunks<St> {
+    pub(super) fn new(stream: St, capacity: usize) -> Chunks<St> {
+        assert!(capacity > 0);
+
+        Chunks {
+            stream: Fuse::new(stream),
+            items: Vec::with_capacity(capacity),
+            cap: capacity,
+        }
+    }
+
+    fn take(items: &mut Vec<St::Item>, cap: usize) -> Vec<St::Item> {
+        mem::replace(items, Vec::with_capacity(cap))
+    }
+}
+
+impl<St: Stream> Stream for Chunks<St> {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    me.items.push(item);
+                    if me.items.len() >= *me.cap {
+                        return Poll::Ready(Some(Self::take(me.items, *me.cap)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    // Flush the partial final batch, if any.
+                    let last = if me.items.is_empty() {
+                        None
+                    } else {
+                        Some(Self::take(me.items, *me.cap))
+                    };
+                    return Poll::Ready(last);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower / self.cap;
+        (lower, upper)
+    }
+}
@@ -0,0 +1,98 @@
+use crate::stream::{Fuse, Stream};
+use crate::time::{Delay, Instant};
+
+use core::future::Future;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::time::Duration;
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the
+    /// [`chunks_timeout`](super::StreamExt::chunks_timeout) method.
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct ChunksTimeout<St>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        #[pin]
+        deadline: Option<Delay>,
+        items: Vec<St::Item>,
+        cap: usize,
+        duration: Duration,
+    }
+}
+
+impl<St: Stream> ChunksTimeout<St> {
+    pub(super) fn new(stream: St, capacity: usize, duration: Duration) -> ChunksTimeout<St> {
+        assert!(capacity > 0);
+
+        ChunksTimeout {
+            stream: Fuse::new(stream),
+            deadline: None,
+            items: Vec::with_capacity(capacity),
+            cap: capacity,
+            duration,
+        }
+    }
+
+    fn take(items: &mut Vec<St::Item>, cap: usize) -> Vec<St::Item> {
+        mem::replace(items, Vec::with_capacity(cap))
+    }
+}
+
+impl<St: Stream> Stream for ChunksTimeout<St> {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if me.items.is_empty() {
+                        // Arm the timer on the first item of a batch.
+                        let next = Instant::now() + *me.duration;
+                        me.deadline.set(Some(Delay::new_timeout(next, *me.duration)));
+                    }
+                    me.items.push(item);
+                    if me.items.len() >= *me.cap {
+                        me.deadline.set(None);
+                        return Poll::Ready(Some(Self::take(me.items, *me.cap)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    let last = if me.items.is_empty() {
+                        None
+                    } else {
+                        me.deadline.set(None);
+                        Some(Self::take(me.items, *me.cap))
+                    };
+                    return Poll::Ready(last);
+                }
+                Poll::Pending => break,
+            }
+        }
+
+        // No more items are immediately available; flush if the timer fires.
+        if let Some(deadline) = me.deadline.as_mut().as_pin_mut() {
+            if deadline.poll(cx).is_ready() {
+                me.deadline.set(None);
+                return Poll::Ready(Some(Self::take(me.items, *me.cap)));
+            }
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower / self.cap;
+        (lower, upper)
+    }
+}
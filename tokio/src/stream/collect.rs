@@ -0,0 +1,330 @@
+use crate::stream::Stream;
+
+use core::future::Future;
+use core::marker::PhantomPinned;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use pin_project_lite::pin_project;
+
+// Do not export this publicly as, for now, it is not consumed directly outside
+// of this crate.
+#[doc(hidden)]
+pub use self::sealed::FromStreamPriv;
+
+/// Conversion from a [`Stream`](crate::stream::Stream).
+///
+/// By implementing `FromStream` for a type, you define how it will be created
+/// from a stream. This is common for types which describe a collection of some
+/// kind.
+///
+/// See [`StreamExt::collect`] for more details.
+///
+/// [`StreamExt::collect`]: crate::stream::StreamExt::collect
+pub trait FromStream<T>: sealed::FromStreamPriv<T> {}
+
+pin_project! {
+    /// Future returned by the [`collect`](super::StreamExt::collect) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    #[derive(Debug)]
+    pub struct Collect<T, U>
+    where
+        T: Stream,
+        U: FromStream<T::Item>,
+    {
+        #[pin]
+        stream: T,
+        collection: U::InternalCollection,
+        // Make this future `!Unpin` for compatibility with async trait methods.
+        #[pin]
+        _pin: PhantomPinned,
+    }
+}
+
+impl<T, U> Collect<T, U>
+where
+    T: Stream,
+    U: FromStream<T::Item>,
+{
+    pub(super) fn new(stream: T) -> Collect<T, U> {
+        let (lower, upper) = stream.size_hint();
+        let collection = U::initialize(sealed::Internal, lower, upper);
+
+        Collect {
+            stream,
+            collection,
+            _pin: PhantomPinned,
+        }
+    }
+}
+
+impl<T, U> Future for Collect<T, U>
+where
+    T: Stream,
+    U: FromStream<T::Item>,
+{
+    type Output = U;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<U> {
+        loop {
+            let me = self.as_mut().project();
+
+            let item = match me.stream.poll_next(cx) {
+                Poll::Ready(item) => item,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match item {
+                Some(item) => {
+                    if !U::extend(sealed::Internal, me.collection, item) {
+                        return Poll::Ready(U::finalize(sealed::Internal, me.collection));
+                    }
+                }
+                None => {
+                    return Poll::Ready(U::finalize(sealed::Internal, me.collection));
+                }
+            }
+        }
+    }
+}
+
+// ===== FromStream implementations =====
+
+impl FromStream<char> for String {}
+
+impl sealed::FromStreamPriv<char> for String {
+    type InternalCollection = String;
+
+    fn initialize(_: sealed::Internal, _lower: usize, _upper: Option<usize>) -> String {
+        String::new()
+    }
+
+    fn extend(_: sealed::Internal, collection: &mut String, item: char) -> bool {
+        collection.push(item);
+        true
+    }
+
+    fn finalize(_: sealed::Internal, collection: &mut String) -> String {
+        mem::replace(collection, String::new())
+    }
+}
+
+impl<'a> FromStream<&'a str> for String {}
+
+impl<'a> sealed::FromStreamPriv<&'a str> for String {
+    type InternalCollection = String;
+
+    fn initialize(_: sealed::Internal, _lower: usize, _upper: Option<usize>) -> String {
+        String::new()
+    }
+
+    fn extend(_: sealed::Internal, collection: &mut String, item: &'a str) -> bool {
+        collection.push_str(item);
+        true
+    }
+
+    fn finalize(_: sealed::Internal, collection: &mut String) -> String {
+        mem::replace(collection, String::new())
+    }
+}
+
+impl<T> FromStream<T> for Vec<T> {}
+
+impl<T> sealed::FromStreamPriv<T> for Vec<T> {
+    type InternalCollection = Vec<T>;
+
+    fn initialize(_: sealed::Internal, lower: usize, _upper: Option<usize>) -> Vec<T> {
+        Vec::with_capacity(lower)
+    }
+
+    fn extend(_: sealed::Internal, collection: &mut Vec<T>, item: T) -> bool {
+        collection.push(item);
+        true
+    }
+
+    fn finalize(_: sealed::Internal, collection: &mut Vec<T>) -> Vec<T> {
+        mem::replace(collection, Vec::new())
+    }
+}
+
+impl<T> FromStream<T> for Box<[T]> {}
+
+impl<T> sealed::FromStreamPriv<T> for Box<[T]> {
+    type InternalCollection = Vec<T>;
+
+    fn initialize(_: sealed::Internal, lower: usize, _upper: Option<usize>) -> Vec<T> {
+        Vec::with_capacity(lower)
+    }
+
+    fn extend(_: sealed::Internal, collection: &mut Vec<T>, item: T) -> bool {
+        collection.push(item);
+        true
+    }
+
+    fn finalize(_: sealed::Internal, collection: &mut Vec<T>) -> Box<[T]> {
+        mem::replace(collection, Vec::new()).into_boxed_slice()
+    }
+}
+
+impl<K, V> FromStream<(K, V)> for HashMap<K, V>
+where
+    K: Eq + Hash,
+{
+}
+
+impl<K, V> sealed::FromStreamPriv<(K, V)> for HashMap<K, V>
+where
+    K: Eq + Hash,
+{
+    type InternalCollection = HashMap<K, V>;
+
+    fn initialize(_: sealed::Internal, lower: usize, _upper: Option<usize>) -> HashMap<K, V> {
+        HashMap::with_capacity(lower)
+    }
+
+    fn extend(_: sealed::Internal, collection: &mut HashMap<K, V>, item: (K, V)) -> bool {
+        collection.insert(item.0, item.1);
+        true
+    }
+
+    fn finalize(_: sealed::Internal, collection: &mut HashMap<K, V>) -> HashMap<K, V> {
+        mem::replace(collection, HashMap::new())
+    }
+}
+
+impl<K, V> FromStream<(K, V)> for BTreeMap<K, V> where K: Ord {}
+
+impl<K, V> sealed::FromStreamPriv<(K, V)> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    type InternalCollection = BTreeMap<K, V>;
+
+    fn initialize(_: sealed::Internal, _lower: usize, _upper: Option<usize>) -> BTreeMap<K, V> {
+        BTreeMap::new()
+    }
+
+    fn extend(_: sealed::Internal, collection: &mut BTreeMap<K, V>, item: (K, V)) -> bool {
+        collection.insert(item.0, item.1);
+        true
+    }
+
+    fn finalize(_: sealed::Internal, collection: &mut BTreeMap<K, V>) -> BTreeMap<K, V> {
+        mem::replace(collection, BTreeMap::new())
+    }
+}
+
+impl<T, U, E> FromStream<Result<T, E>> for Result<U, E> where U: FromStream<T> {}
+
+impl<T, U, E> sealed::FromStreamPriv<Result<T, E>> for Result<U, E>
+where
+    U: FromStream<T>,
+{
+    type InternalCollection = Result<U::InternalCollection, E>;
+
+    fn initialize(
+        _: sealed::Internal,
+        lower: usize,
+        upper: Option<usize>,
+    ) -> Result<U::InternalCollection, E> {
+        Ok(U::initialize(sealed::Internal, lower, upper))
+    }
+
+    fn extend(
+        _: sealed::Internal,
+        collection: &mut Result<U::InternalCollection, E>,
+        item: Result<T, E>,
+    ) -> bool {
+        assert!(collection.is_ok());
+        let res = collection.as_mut().ok().expect("invalid state");
+
+        match item {
+            Ok(item) => U::extend(sealed::Internal, res, item),
+            Err(err) => {
+                *collection = Err(err);
+                false
+            }
+        }
+    }
+
+    fn finalize(
+        _: sealed::Internal,
+        collection: &mut Result<U::InternalCollection, E>,
+    ) -> Result<U, E> {
+        if let Ok(collection) = collection.as_mut() {
+            Ok(U::finalize(sealed::Internal, collection))
+        } else {
+            let res = mem::replace(collection, Ok(U::initialize(sealed::Internal, 0, Some(0))));
+            Err(res.map(drop).unwrap_err())
+        }
+    }
+}
+
+impl<T, U> FromStream<Option<T>> for Option<U> where U: FromStream<T> {}
+
+impl<T, U> sealed::FromStreamPriv<Option<T>> for Option<U>
+where
+    U: FromStream<T>,
+{
+    type InternalCollection = Option<U::InternalCollection>;
+
+    fn initialize(
+        _: sealed::Internal,
+        lower: usize,
+        upper: Option<usize>,
+    ) -> Option<U::InternalCollection> {
+        Some(U::initialize(sealed::Internal, lower, upper))
+    }
+
+    fn extend(
+        _: sealed::Internal,
+        collection: &mut Option<U::InternalCollection>,
+        item: Option<T>,
+    ) -> bool {
+        assert!(collection.is_some());
+        let res = collection.as_mut().expect("invalid state");
+
+        match item {
+            Some(item) => U::extend(sealed::Internal, res, item),
+            None => {
+                *collection = None;
+                false
+            }
+        }
+    }
+
+    fn finalize(_: sealed::Internal, collection: &mut Option<U::InternalCollection>) -> Option<U> {
+        collection
+            .as_mut()
+            .map(|collection| U::finalize(sealed::Internal, collection))
+    }
+}
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait FromStreamPriv<T> {
+        /// Intermediate type used during collection process
+        type InternalCollection;
+
+        /// Initialize the collection
+        fn initialize(
+            internal: Internal,
+            lower: usize,
+            upper: Option<usize>,
+        ) -> Self::InternalCollection;
+
+        /// Extend the collection with the received item
+        ///
+        /// Return `true` to continue streaming, `false` complete collection.
+        fn extend(internal: Internal, collection: &mut Self::InternalCollection, item: T) -> bool;
+
+        /// Finalize collection into target type.
+        fn finalize(internal: Internal, collection: &mut Self::InternalCollection) -> Self;
+    }
+
+    #[allow(missing_debug_implementations)]
+    pub struct Internal;
+}
@@ -0,0 +1,55 @@
+use crate::stream::Stream;
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future returned by the [`fold`](super::StreamExt::fold) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Fold<St, B, F> {
+        #[pin]
+        stream: St,
+        acc: Option<B>,
+        f: F,
+    }
+}
+
+impl<St, B, F> Fold<St, B, F>
+where
+    St: Stream,
+    F: FnMut(B, St::Item) -> B,
+{
+    pub(super) fn new(stream: St, init: B, f: F) -> Fold<St, B, F> {
+        Fold {
+            stream,
+            acc: Some(init),
+            f,
+        }
+    }
+}
+
+impl<St, B, F> Future for Fold<St, B, F>
+where
+    St: Stream,
+    F: FnMut(B, St::Item) -> B,
+{
+    type Output = B;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+        let mut me = self.project();
+        loop {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let acc = me.acc.take().unwrap();
+                    *me.acc = Some((me.f)(acc, item));
+                }
+                Poll::Ready(None) => return Poll::Ready(me.acc.take().unwrap()),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::stream::Stream;
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future returned by the [`for_each`](super::StreamExt::for_each) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ForEach<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+    }
+}
+
+impl<St, F> ForEach<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item),
+{
+    pub(super) fn new(stream: St, f: F) -> ForEach<St, F> {
+        ForEach { stream, f }
+    }
+}
+
+impl<St, F> Future for ForEach<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item),
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut me = self.project();
+        loop {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => (me.f)(item),
+                Poll::Ready(None) => return Poll::Ready(()),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -10,15 +10,45 @@ use all::AllFuture;
 mod any;
 use any::AnyFuture;
 
+mod buffered;
+use buffered::Buffered;
+
+mod buffer_unordered;
+use buffer_unordered::BufferUnordered;
+
 mod chain;
 use chain::Chain;
 
+mod chunks;
+use chunks::Chunks;
+
+#[cfg(feature = "time")]
+mod chunks_timeout;
+#[cfg(feature = "time")]
+use chunks_timeout::ChunksTimeout;
+
+mod collect;
+use collect::Collect;
+pub use collect::FromStream;
+
 mod empty;
 pub use empty::{empty, Empty};
 
+mod enumerate;
+use enumerate::Enumerate;
+
 mod filter;
 use filter::Filter;
 
+mod fold;
+use fold::Fold;
+
+mod for_each;
+use for_each::ForEach;
+
+mod try_for_each;
+use try_for_each::TryForEach;
+
 mod filter_map;
 use filter_map::FilterMap;
 
@@ -46,12 +76,36 @@ pub use pending::{pending, Pending};
 mod try_next;
 use try_next::TryNext;
 
+mod scan;
+use scan::Scan;
+
+mod skip;
+use skip::Skip;
+
+mod skip_while;
+use skip_while::SkipWhile;
+
+mod step_by;
+use step_by::StepBy;
+
 mod take;
 use take::Take;
 
+#[cfg(feature = "time")]
+mod timeout;
+#[cfg(feature = "time")]
+use timeout::Timeout;
+#[cfg(feature = "time")]
+use std::time::Duration;
+
 mod take_while;
 use take_while::TakeWhile;
 
+mod zip;
+use zip::Zip;
+
+use std::future::Future;
+
 pub use futures_core::Stream;
 
 /// An extension trait for `Stream`s that provides a variety of convenient
@@ -405,6 +459,118 @@ pub trait StreamExt: Stream {
         Take::new(self, n)
     }
 
+    /// Creates a new stream that yields the current count of the item paired
+    /// with the item, where the count starts at zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(vec!['a', 'b', 'c']).enumerate();
+    ///
+    /// assert_eq!(stream.next().await, Some((0, 'a')));
+    /// assert_eq!(stream.next().await, Some((1, 'b')));
+    /// assert_eq!(stream.next().await, Some((2, 'c')));
+    /// assert_eq!(stream.next().await, None);
+    /// # }
+    /// ```
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate::new(self)
+    }
+
+    /// Creates a new stream that skips the first `n` items of the underlying
+    /// stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=10).skip(7);
+    ///
+    /// assert_eq!(Some(8), stream.next().await);
+    /// assert_eq!(Some(9), stream.next().await);
+    /// assert_eq!(Some(10), stream.next().await);
+    /// assert_eq!(None, stream.next().await);
+    /// # }
+    /// ```
+    fn skip(self, n: usize) -> Skip<Self>
+    where
+        Self: Sized,
+    {
+        Skip::new(self, n)
+    }
+
+    /// Skip elements from the underlying stream while the provided predicate
+    /// resolves to `true`.
+    ///
+    /// This function, like [`Iterator::skip_while`], will ignore elements from
+    /// the stream until the predicate `f` resolves to `false`. Once one element
+    /// returns `false`, the rest of the elements will be yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=10).skip_while(|x| *x <= 3);
+    ///
+    /// assert_eq!(Some(4), stream.next().await);
+    /// assert_eq!(Some(5), stream.next().await);
+    /// assert_eq!(Some(6), stream.next().await);
+    /// # }
+    /// ```
+    fn skip_while<F>(self, f: F) -> SkipWhile<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        SkipWhile::new(self, f)
+    }
+
+    /// Creates a new stream that yields the first element and then every
+    /// `step`-th element thereafter.
+    ///
+    /// The first element is always yielded, then elements are skipped in
+    /// groups of `step - 1`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `step` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=10).step_by(3);
+    ///
+    /// assert_eq!(Some(1), stream.next().await);
+    /// assert_eq!(Some(4), stream.next().await);
+    /// assert_eq!(Some(7), stream.next().await);
+    /// assert_eq!(Some(10), stream.next().await);
+    /// assert_eq!(None, stream.next().await);
+    /// # }
+    /// ```
+    fn step_by(self, step: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+    {
+        StepBy::new(self, step)
+    }
+
     /// Take elements from this stream while the provided predicate
     /// resolves to `true`.
     ///
@@ -435,6 +601,47 @@ pub trait StreamExt: Stream {
         TakeWhile::new(self, f)
     }
 
+    /// A stream adaptor similar to [`fold`](StreamExt::fold) that holds
+    /// internal state and produces a new stream.
+    ///
+    /// `scan()` takes two arguments: an initial value which seeds the internal
+    /// state, and a closure with two arguments, the first being a mutable
+    /// reference to the internal state and the second a stream element. The
+    /// closure can assign to the internal state to share state between
+    /// iterations.
+    ///
+    /// On iteration, the closure will be applied to each element of the stream
+    /// and the return value from the closure, an [`Option`], is yielded by the
+    /// stream. Returning [`None`] from the closure terminates the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// let mut stream = stream.scan(1, |state, x| {
+    ///     // each iteration, we'll multiply the state by the element
+    ///     *state = *state * x;
+    ///     Some(*state)
+    /// });
+    ///
+    /// assert_eq!(stream.next().await, Some(1));
+    /// assert_eq!(stream.next().await, Some(2));
+    /// assert_eq!(stream.next().await, Some(6));
+    /// assert_eq!(stream.next().await, None);
+    /// # }
+    /// ```
+    fn scan<S, B, F>(self, initial_state: S, f: F) -> Scan<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut S, Self::Item) -> Option<B>,
+    {
+        Scan::new(self, initial_state, f)
+    }
+
     /// Tests if every element of the stream matches a predicate.
     ///
     /// `all()` takes a closure that returns `true` or `false`. It applies
@@ -577,6 +784,358 @@ pub trait StreamExt: Stream {
     {
         Chain::new(self, other)
     }
+
+    /// Combine two streams into one by walking them in lockstep, yielding a
+    /// pair of values, one from each stream, at each step.
+    ///
+    /// The zipped stream waits for both sources to produce a value before
+    /// emitting the pair, buffering whichever value arrives first. It completes
+    /// as soon as **either** source stream completes; if one stream ends while
+    /// a value from the other is still buffered, that orphaned value is
+    /// discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let one = stream::iter(vec![1, 2, 3]);
+    ///     let two = stream::iter(vec![4, 5, 6]);
+    ///
+    ///     let mut stream = one.zip(two);
+    ///
+    ///     assert_eq!(stream.next().await, Some((1, 4)));
+    ///     assert_eq!(stream.next().await, Some((2, 5)));
+    ///     assert_eq!(stream.next().await, Some((3, 6)));
+    ///     assert_eq!(stream.next().await, None);
+    /// }
+    /// ```
+    fn zip<U>(self, other: U) -> Zip<Self, U>
+    where
+        U: Stream,
+        Self: Sized,
+    {
+        Zip::new(self, other)
+    }
+
+    /// An adaptor for creating a buffered list of pending futures.
+    ///
+    /// When `Self::Item` is a [`Future`], this combinator will attempt to pull
+    /// up to `n` futures from the underlying stream and poll them concurrently.
+    /// The outputs are yielded **in the same order** as the futures were pulled
+    /// from the source: the head future must complete before any later one is
+    /// emitted, even if it finishes first. As soon as capacity frees up, more
+    /// futures are pulled from the source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![3, 1, 2]).map(|n| async move { n });
+    /// let mut buffered = stream.buffered(3);
+    ///
+    /// assert_eq!(buffered.next().await, Some(3));
+    /// assert_eq!(buffered.next().await, Some(1));
+    /// assert_eq!(buffered.next().await, Some(2));
+    /// assert_eq!(buffered.next().await, None);
+    /// # }
+    /// ```
+    fn buffered(self, n: usize) -> Buffered<Self>
+    where
+        Self: Sized,
+        Self::Item: Future,
+    {
+        Buffered::new(self, n)
+    }
+
+    /// An adaptor for creating a buffered list of pending futures (unordered).
+    ///
+    /// This is identical to [`buffered`](StreamExt::buffered) except that the
+    /// outputs are yielded in the order the futures *complete* rather than the
+    /// order they were pulled from the source. Up to `n` futures are driven
+    /// concurrently and the set is eagerly refilled from the source whenever
+    /// capacity is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2, 3]).map(|n| async move { n });
+    /// let mut buffered = stream.buffer_unordered(3);
+    ///
+    /// let mut out = Vec::new();
+    /// while let Some(n) = buffered.next().await {
+    ///     out.push(n);
+    /// }
+    /// out.sort();
+    ///
+    /// assert_eq!(out, vec![1, 2, 3]);
+    /// # }
+    /// ```
+    fn buffer_unordered(self, n: usize) -> BufferUnordered<Self>
+    where
+        Self: Sized,
+        Self::Item: Future,
+    {
+        BufferUnordered::new(self, n)
+    }
+
+    /// Drain stream pushing all emitted values into a collection.
+    ///
+    /// `collect` streams all values, awaiting as needed. Values are pushed into
+    /// a collection. A number of different target collection types are
+    /// supported, including [`Vec`](std::vec::Vec),
+    /// [`String`](std::string::String), `Box<[T]>`,
+    /// [`HashMap`](std::collections::HashMap) and
+    /// [`BTreeMap`](std::collections::BTreeMap).
+    ///
+    /// # `Result`
+    ///
+    /// `collect()` can also be used with streams of type `Result<T, E>` where
+    /// `T: FromStream<_>`. In this case, `collect()` will stream as long as
+    /// values yielded from the stream are `Ok(_)`. If `Err(_)` is encountered,
+    /// streaming is terminated and `collect()` returns the `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let doubled: Vec<i32> =
+    ///         stream::iter(vec![1, 2, 3])
+    ///             .map(|x| x * 2)
+    ///             .collect()
+    ///             .await;
+    ///
+    ///     assert_eq!(vec![2, 4, 6], doubled);
+    /// }
+    /// ```
+    ///
+    /// Collecting a stream of `Result` values
+    ///
+    /// ```
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // A stream containing only `Ok` values will be collected
+    ///     let values: Result<Vec<i32>, &str> =
+    ///         stream::iter(vec![Ok(1), Ok(2), Ok(3)])
+    ///             .collect()
+    ///             .await;
+    ///
+    ///     assert_eq!(Ok(vec![1, 2, 3]), values);
+    ///
+    ///     // A stream containing `Err` values will return the first error.
+    ///     let results = vec![Ok(1), Err("no"), Ok(3), Err("nope")];
+    ///
+    ///     let values: Result<Vec<i32>, &str> =
+    ///         stream::iter(results)
+    ///             .collect()
+    ///             .await;
+    ///
+    ///     assert_eq!(Err("no"), values);
+    /// }
+    /// ```
+    fn collect<T>(self) -> Collect<Self, T>
+    where
+        T: FromStream<Self::Item>,
+        Self: Sized,
+    {
+        Collect::new(self)
+    }
+
+    /// A combinator that applies a function to every element in a stream
+    /// producing a single, final value.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let s = stream::iter(vec![1u8, 2, 3]);
+    /// let sum = s.fold(0, |acc, x| acc + x).await;
+    ///
+    /// assert_eq!(sum, 6);
+    /// # }
+    /// ```
+    fn fold<B, F>(self, init: B, f: F) -> Fold<Self, B, F>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        Fold::new(self, init, f)
+    }
+
+    /// Calls a closure on each element of this stream.
+    ///
+    /// The closure is called on every element produced by the stream. The
+    /// returned future completes once the stream has been fully drained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    /// use std::sync::mpsc::channel;
+    ///
+    /// let (tx, rx) = channel();
+    ///
+    /// stream::iter(vec![1, 2, 3])
+    ///     .for_each(move |x| tx.send(x).unwrap())
+    ///     .await;
+    ///
+    /// let received: Vec<i32> = rx.iter().collect();
+    ///
+    /// assert_eq!(received, vec![1, 2, 3]);
+    /// # }
+    /// ```
+    fn for_each<F>(self, f: F) -> ForEach<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item),
+    {
+        ForEach::new(self, f)
+    }
+
+    /// Calls a fallible closure on each element of this stream, stopping at the
+    /// first error.
+    ///
+    /// The closure is applied to every element until it returns an
+    /// [`Err`](Result), at which point iteration is terminated and the error is
+    /// returned. If every element is processed successfully, the returned
+    /// future completes with [`Ok(())`](Result).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let res = stream::iter(vec![1, 2, 3])
+    ///     .try_for_each(|x| if x < 3 { Ok(()) } else { Err(x) })
+    ///     .await;
+    ///
+    /// assert_eq!(res, Err(3));
+    /// # }
+    /// ```
+    fn try_for_each<F, E>(self, f: F) -> TryForEach<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<(), E>,
+    {
+        TryForEach::new(self, f)
+    }
+
+    /// Applies a per-item timeout to the passed stream.
+    ///
+    /// `timeout()` takes a `Duration` that represents the maximum amount of
+    /// time each element of the stream has to complete before timing out.
+    ///
+    /// If the wrapped stream yields a value before the deadline is reached, the
+    /// value is returned wrapped in `Ok`. If the deadline is reached before the
+    /// next value is yielded, an error is returned in its place. Either way, the
+    /// timer is reset for the next value and the underlying stream continues to
+    /// be polled; a timeout does **not** terminate the stream.
+    ///
+    /// # Notes
+    ///
+    /// This function consumes the stream passed into it and returns a wrapped
+    /// version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    /// use std::time::Duration;
+    /// # let int_stream = stream::iter(1..=3);
+    ///
+    /// let int_stream = int_stream.timeout(Duration::from_secs(1));
+    /// tokio::pin!(int_stream);
+    ///
+    /// // When no items time out, we get the 3 elements in succession:
+    /// assert_eq!(int_stream.try_next().await, Ok(Some(1)));
+    /// assert_eq!(int_stream.try_next().await, Ok(Some(2)));
+    /// assert_eq!(int_stream.try_next().await, Ok(Some(3)));
+    /// assert_eq!(int_stream.try_next().await, Ok(None));
+    /// # }
+    /// ```
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    fn timeout(self, duration: Duration) -> Timeout<Self>
+    where
+        Self: Sized,
+    {
+        Timeout::new(self, duration)
+    }
+
+    /// Batches the items of the stream, yielding a `Vec` once `capacity` items
+    /// have accumulated.
+    ///
+    /// The last batch is flushed when the underlying stream ends, even if it
+    /// holds fewer than `capacity` items. An empty source yields no batches.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tokio::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=5).chunks(2);
+    ///
+    /// assert_eq!(stream.next().await, Some(vec![1, 2]));
+    /// assert_eq!(stream.next().await, Some(vec![3, 4]));
+    /// assert_eq!(stream.next().await, Some(vec![5]));
+    /// assert_eq!(stream.next().await, None);
+    /// # }
+    /// ```
+    fn chunks(self, capacity: usize) -> Chunks<Self>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self, capacity)
+    }
+
+    /// Batches the items of the stream, flushing a `Vec` as soon as either
+    /// `capacity` items accumulate or `duration` elapses since the first item
+    /// of the current batch.
+    ///
+    /// This is useful to bound the latency of batched work (for example
+    /// flushing network writes) while still coalescing under load. The timer is
+    /// armed when the first item of a batch arrives and cleared on every flush.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    fn chunks_timeout(self, capacity: usize, duration: Duration) -> ChunksTimeout<Self>
+    where
+        Self: Sized,
+    {
+        ChunksTimeout::new(self, capacity, duration)
+    }
 }
 
 impl<St: ?Sized> StreamExt for St where St: Stream {}
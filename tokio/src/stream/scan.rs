@@ -0,0 +1,71 @@
+use crate::stream::Stream;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`scan`](super::StreamExt::scan) method.
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct Scan<St, S, F> {
+        #[pin]
+        stream: St,
+        state: S,
+        f: F,
+        done: bool,
+    }
+}
+
+impl<St, S, F> Scan<St, S, F> {
+    pub(super) fn new(stream: St, initial_state: S, f: F) -> Scan<St, S, F> {
+        Scan {
+            stream,
+            state: initial_state,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<St, S, B, F> Stream for Scan<St, S, F>
+where
+    St: Stream,
+    F: FnMut(&mut S, St::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<B>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        let me = self.project();
+
+        match me.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => match (me.f)(me.state, item) {
+                Some(item) => Poll::Ready(Some(item)),
+                None => {
+                    *me.done = true;
+                    Poll::Ready(None)
+                }
+            },
+            Poll::Ready(None) => {
+                *me.done = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.stream.size_hint();
+            // The stream may terminate early, so the lower bound is zero.
+            (0, upper)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::stream::Stream;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`step_by`](super::StreamExt::step_by) method.
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct StepBy<St> {
+        #[pin]
+        stream: St,
+        stride: usize,
+        skip: usize,
+    }
+}
+
+impl<St> StepBy<St> {
+    pub(super) fn new(stream: St, step: usize) -> StepBy<St> {
+        assert!(step > 0);
+
+        StepBy {
+            stream,
+            stride: step - 1,
+            skip: 0,
+        }
+    }
+}
+
+impl<St: Stream> Stream for StepBy<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if *me.skip == 0 {
+                        *me.skip = *me.stride;
+                        return Poll::Ready(Some(item));
+                    }
+                    *me.skip -= 1;
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.stream.size_hint();
+        let step = self.stride + 1;
+        (0, upper.map(|upper| upper / step + 1))
+    }
+}
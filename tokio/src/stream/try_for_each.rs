@@ -0,0 +1,52 @@
+use crate::stream::Stream;
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future returned by the [`try_for_each`](super::StreamExt::try_for_each)
+    /// method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryForEach<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+    }
+}
+
+impl<St, F, E> TryForEach<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Result<(), E>,
+{
+    pub(super) fn new(stream: St, f: F) -> TryForEach<St, F> {
+        TryForEach { stream, f }
+    }
+}
+
+impl<St, F, E> Future for TryForEach<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Result<(), E>,
+{
+    type Output = Result<(), E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), E>> {
+        let mut me = self.project();
+        loop {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if let Err(e) = (me.f)(item) {
+                        return Poll::Ready(Err(e));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
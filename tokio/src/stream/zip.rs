@@ -0,0 +1,91 @@
+use crate::stream::{Fuse, Stream};
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream returned by the [`zip`](super::StreamExt::zip) method.
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct Zip<T, U>
+    where
+        T: Stream,
+        U: Stream,
+    {
+        #[pin]
+        first: Fuse<T>,
+        #[pin]
+        second: Fuse<U>,
+        queued_first: Option<T::Item>,
+        queued_second: Option<U::Item>,
+    }
+}
+
+impl<T, U> Zip<T, U>
+where
+    T: Stream,
+    U: Stream,
+{
+    pub(super) fn new(first: T, second: U) -> Zip<T, U> {
+        Zip {
+            first: Fuse::new(first),
+            second: Fuse::new(second),
+            queued_first: None,
+            queued_second: None,
+        }
+    }
+}
+
+impl<T, U> Stream for Zip<T, U>
+where
+    T: Stream,
+    U: Stream,
+{
+    type Item = (T::Item, U::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if me.queued_first.is_none() {
+            match me.first.poll_next(cx) {
+                Poll::Ready(Some(item)) => *me.queued_first = Some(item),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => {}
+            }
+        }
+
+        if me.queued_second.is_none() {
+            match me.second.poll_next(cx) {
+                Poll::Ready(Some(item)) => *me.queued_second = Some(item),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => {}
+            }
+        }
+
+        if me.queued_first.is_some() && me.queued_second.is_some() {
+            let first = me.queued_first.take().unwrap();
+            let second = me.queued_second.take().unwrap();
+            Poll::Ready(Some((first, second)))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_lower, first_upper) = self.first.size_hint();
+        let (second_lower, second_upper) = self.second.size_hint();
+
+        let lower = first_lower.min(second_lower);
+
+        let upper = match (first_upper, second_upper) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None,
+        };
+
+        (lower, upper)
+    }
+}
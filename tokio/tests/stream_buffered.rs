@@ -0,0 +1,73 @@
+use tokio::stream::{self, StreamExt};
+use tokio::sync::oneshot;
+use tokio_test::*;
+
+#[tokio::test]
+async fn buffered_preserves_order_under_out_of_order_completion() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let (tx3, rx3) = oneshot::channel::<i32>();
+
+    let futs = vec![
+        async { rx1.await.unwrap() },
+        async { rx2.await.unwrap() },
+        async { rx3.await.unwrap() },
+    ];
+
+    let mut stream = task::spawn(stream::iter(futs).buffered(3));
+
+    // Nothing completes yet even though all three futures are in flight.
+    assert_pending!(stream.poll_next());
+
+    // Complete out of order: the tail finishes first.
+    tx3.send(3).unwrap();
+    tx2.send(2).unwrap();
+    assert_pending!(stream.poll_next());
+
+    // The head completes last; order is still preserved on output.
+    tx1.send(1).unwrap();
+    assert_ready_eq!(stream.poll_next(), Some(1));
+    assert_ready_eq!(stream.poll_next(), Some(2));
+    assert_ready_eq!(stream.poll_next(), Some(3));
+    assert_ready_eq!(stream.poll_next(), None);
+}
+
+#[tokio::test]
+async fn buffer_unordered_yields_as_completed() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let (tx3, rx3) = oneshot::channel::<i32>();
+
+    let futs = vec![
+        async { rx1.await.unwrap() },
+        async { rx2.await.unwrap() },
+        async { rx3.await.unwrap() },
+    ];
+
+    let mut stream = task::spawn(stream::iter(futs).buffer_unordered(3));
+
+    assert_pending!(stream.poll_next());
+
+    // The second future finishes first and is yielded first.
+    tx2.send(2).unwrap();
+    assert_ready_eq!(stream.poll_next(), Some(2));
+
+    tx1.send(1).unwrap();
+    tx3.send(3).unwrap();
+    let a = assert_ready!(stream.poll_next()).unwrap();
+    let b = assert_ready!(stream.poll_next()).unwrap();
+    assert_eq!([a, b].iter().copied().min(), Some(1));
+    assert_eq!([a, b].iter().copied().max(), Some(3));
+
+    assert_ready_eq!(stream.poll_next(), None);
+}
+
+#[tokio::test]
+async fn buffer_unordered_refills_from_source() {
+    let futs = (1..=5).map(|n| async move { n });
+    let out: Vec<i32> = stream::iter(futs).buffer_unordered(2).collect().await;
+
+    let mut out = out;
+    out.sort_unstable();
+    assert_eq!(out, vec![1, 2, 3, 4, 5]);
+}
@@ -0,0 +1,54 @@
+use tokio::stream::{self, StreamExt};
+
+#[tokio::test]
+async fn chunks() {
+    let mut stream = stream::iter(1..=5).chunks(2);
+
+    assert_eq!(stream.next().await, Some(vec![1, 2]));
+    assert_eq!(stream.next().await, Some(vec![3, 4]));
+    // Partial final batch is flushed.
+    assert_eq!(stream.next().await, Some(vec![5]));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn chunks_exact() {
+    let mut stream = stream::iter(1..=4).chunks(2);
+
+    assert_eq!(stream.next().await, Some(vec![1, 2]));
+    assert_eq!(stream.next().await, Some(vec![3, 4]));
+    assert_eq!(stream.next().await, None);
+}
+
+#[cfg(feature = "time")]
+mod chunks_timeout {
+    use tokio::stream::StreamExt;
+    use tokio::time::{self, Duration};
+    use tokio_test::*;
+
+    #[tokio::test]
+    async fn flushes_on_capacity() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<i32>();
+        let mut stream = task::spawn(rx.chunks_timeout(2, Duration::from_millis(100)));
+
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        assert_ready_eq!(stream.poll_next(), Some(vec![1, 2]));
+    }
+
+    #[tokio::test]
+    async fn flushes_on_timeout() {
+        time::pause();
+
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<i32>();
+        let mut stream = task::spawn(rx.chunks_timeout(4, Duration::from_millis(100)));
+
+        // First item of the batch arms the timer.
+        tx.send(1).unwrap();
+        assert_pending!(stream.poll_next());
+
+        // Capacity not reached, but the timer fires and flushes the partial batch.
+        time::advance(Duration::from_millis(150)).await;
+        assert_ready_eq!(stream.poll_next(), Some(vec![1]));
+    }
+}
@@ -0,0 +1,58 @@
+use tokio::stream;
+use tokio::stream::StreamExt;
+
+use std::collections::{BTreeMap, HashMap};
+
+#[tokio::test]
+async fn collect_vec() {
+    let v: Vec<i32> = stream::iter(1..=3).collect().await;
+    assert_eq!(v, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn collect_string() {
+    let s: String = stream::iter(vec!['a', 'b', 'c']).collect().await;
+    assert_eq!(s, "abc");
+
+    let s: String = stream::iter(vec!["foo", "bar"]).collect().await;
+    assert_eq!(s, "foobar");
+}
+
+#[tokio::test]
+async fn collect_boxed_slice() {
+    let s: Box<[i32]> = stream::iter(1..=3).collect().await;
+    assert_eq!(&s[..], &[1, 2, 3]);
+}
+
+#[tokio::test]
+async fn collect_map() {
+    let map: HashMap<i32, i32> = stream::iter(vec![(1, 2), (3, 4)]).collect().await;
+    assert_eq!(map.get(&1), Some(&2));
+    assert_eq!(map.get(&3), Some(&4));
+
+    let map: BTreeMap<i32, i32> = stream::iter(vec![(1, 2), (3, 4)]).collect().await;
+    assert_eq!(map.get(&1), Some(&2));
+    assert_eq!(map.get(&3), Some(&4));
+}
+
+#[tokio::test]
+async fn collect_result_ok() {
+    let res: Result<Vec<i32>, ()> = stream::iter(vec![Ok(1), Ok(2), Ok(3)]).collect().await;
+    assert_eq!(res, Ok(vec![1, 2, 3]));
+}
+
+#[tokio::test]
+async fn collect_result_err() {
+    let res: Result<Vec<i32>, &str> =
+        stream::iter(vec![Ok(1), Err("nope"), Ok(3)]).collect().await;
+    assert_eq!(res, Err("nope"));
+}
+
+#[tokio::test]
+async fn collect_option() {
+    let res: Option<Vec<i32>> = stream::iter(vec![Some(1), Some(2)]).collect().await;
+    assert_eq!(res, Some(vec![1, 2]));
+
+    let res: Option<Vec<i32>> = stream::iter(vec![Some(1), None, Some(3)]).collect().await;
+    assert_eq!(res, None);
+}
@@ -0,0 +1,55 @@
+use tokio::stream::{self, StreamExt};
+
+#[tokio::test]
+async fn enumerate() {
+    let mut stream = stream::iter(vec!['a', 'b', 'c']).enumerate();
+
+    assert_eq!(stream.next().await, Some((0, 'a')));
+    assert_eq!(stream.next().await, Some((1, 'b')));
+    assert_eq!(stream.next().await, Some((2, 'c')));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn skip() {
+    let mut stream = stream::iter(1..=5).skip(2);
+
+    assert_eq!(stream.next().await, Some(3));
+    assert_eq!(stream.next().await, Some(4));
+    assert_eq!(stream.next().await, Some(5));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn skip_more_than_available() {
+    let mut stream = stream::iter(1..=3).skip(5);
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn skip_while() {
+    let mut stream = stream::iter(vec![1, 2, 3, 1, 2]).skip_while(|&x| x < 3);
+
+    // Drops leading items until the predicate is false, then forwards all.
+    assert_eq!(stream.next().await, Some(3));
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(stream.next().await, Some(2));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn step_by() {
+    let mut stream = stream::iter(0..=10).step_by(3);
+
+    assert_eq!(stream.next().await, Some(0));
+    assert_eq!(stream.next().await, Some(3));
+    assert_eq!(stream.next().await, Some(6));
+    assert_eq!(stream.next().await, Some(9));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+#[should_panic]
+async fn step_by_zero_panics() {
+    let _ = stream::iter(0..=10).step_by(0);
+}
@@ -0,0 +1,46 @@
+use tokio::stream;
+use tokio::stream::StreamExt;
+
+#[tokio::test]
+async fn fold() {
+    let sum = stream::iter(1..=5).fold(0, |acc, x| acc + x).await;
+    assert_eq!(sum, 15);
+}
+
+#[tokio::test]
+async fn fold_empty() {
+    let sum = stream::iter(Vec::<i32>::new()).fold(42, |acc, x| acc + x).await;
+    assert_eq!(sum, 42);
+}
+
+#[tokio::test]
+async fn for_each() {
+    let mut seen = vec![];
+    stream::iter(1..=3).for_each(|x| seen.push(x)).await;
+    assert_eq!(seen, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn try_for_each_ok() {
+    let res: Result<(), ()> = stream::iter(1..=3).try_for_each(|_| Ok(())).await;
+    assert_eq!(res, Ok(()));
+}
+
+#[tokio::test]
+async fn try_for_each_stops_on_err() {
+    let mut seen = vec![];
+    let res: Result<(), i32> = stream::iter(1..=5)
+        .try_for_each(|x| {
+            seen.push(x);
+            if x == 3 {
+                Err(x)
+            } else {
+                Ok(())
+            }
+        })
+        .await;
+
+    assert_eq!(res, Err(3));
+    // The closure must stop at the first failure.
+    assert_eq!(seen, vec![1, 2, 3]);
+}
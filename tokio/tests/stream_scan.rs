@@ -0,0 +1,34 @@
+use tokio::stream::{self, StreamExt};
+
+#[tokio::test]
+async fn scan_running_total() {
+    let mut stream = stream::iter(1..=4).scan(0, |acc, x| {
+        *acc += x;
+        Some(*acc)
+    });
+
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(stream.next().await, Some(3));
+    assert_eq!(stream.next().await, Some(6));
+    assert_eq!(stream.next().await, Some(10));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn scan_terminates_early() {
+    let mut stream = stream::iter(1..=10).scan(0, |acc, x| {
+        *acc += x;
+        if *acc > 5 {
+            None
+        } else {
+            Some(*acc)
+        }
+    });
+
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(stream.next().await, Some(3));
+    // 1 + 2 + 3 = 6 > 5, so the stream ends.
+    assert_eq!(stream.next().await, None);
+    // Remains terminated without re-polling the source.
+    assert_eq!(stream.next().await, None);
+}
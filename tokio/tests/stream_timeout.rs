@@ -0,0 +1,39 @@
+#![cfg(feature = "time")]
+
+use tokio::stream::{self, StreamExt};
+use tokio::time::{self, Duration};
+use tokio_test::*;
+
+#[tokio::test]
+async fn basic_usage() {
+    time::pause();
+
+    // Stream of immediately ready values; no timeout should fire.
+    let stream = stream::iter(1..=3).timeout(Duration::from_millis(100));
+    let mut stream = task::spawn(stream);
+
+    assert_ready_eq!(stream.poll_next(), Some(Ok(1)));
+    assert_ready_eq!(stream.poll_next(), Some(Ok(2)));
+    assert_ready_eq!(stream.poll_next(), Some(Ok(3)));
+    assert_ready_eq!(stream.poll_next(), None);
+}
+
+#[tokio::test]
+async fn times_out_slow_stream() {
+    time::pause();
+
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<i32>();
+    let stream = rx.timeout(Duration::from_millis(100));
+    let mut stream = task::spawn(stream);
+
+    // Nothing available yet and the timer has not fired.
+    assert_pending!(stream.poll_next());
+
+    // Advancing past the deadline arms an `Elapsed` error but keeps polling.
+    time::advance(Duration::from_millis(150)).await;
+    assert_ready!(stream.poll_next()).unwrap().unwrap_err();
+
+    // A subsequent item comes through and the timer is rearmed.
+    tx.send(1).unwrap();
+    assert_ready_eq!(stream.poll_next(), Some(Ok(1)));
+}
@@ -0,0 +1,27 @@
+use tokio::stream::{self, StreamExt};
+
+#[tokio::test]
+async fn zip() {
+    let a = stream::iter(vec![1, 2, 3]);
+    let b = stream::iter(vec![4, 5, 6]);
+
+    let mut stream = a.zip(b);
+
+    assert_eq!(stream.next().await, Some((1, 4)));
+    assert_eq!(stream.next().await, Some((2, 5)));
+    assert_eq!(stream.next().await, Some((3, 6)));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn zip_stops_on_shorter_side() {
+    let a = stream::iter(vec![1, 2, 3, 4]);
+    let b = stream::iter(vec![4, 5]);
+
+    let mut stream = a.zip(b);
+
+    // Completes as soon as the shorter side ends; the orphaned `3` is discarded.
+    assert_eq!(stream.next().await, Some((1, 4)));
+    assert_eq!(stream.next().await, Some((2, 5)));
+    assert_eq!(stream.next().await, None);
+}